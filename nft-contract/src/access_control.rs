@@ -0,0 +1,110 @@
+use std::collections::HashSet;
+
+use crate::*;
+
+/// Roles that can be granted to an account. `Owner` is reserved for the
+/// account stored in `Contract::owner_id` and is never stored in `roles`.
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub enum Role {
+    Owner,
+    Creator,
+    Minter,
+    Admin,
+}
+
+#[near_bindgen]
+impl Contract {
+    /// Grant `role` to `account_id`. Restricted to admins (and the owner).
+    pub fn grant_role(&mut self, account_id: AccountId, role: Role) {
+        self.assert_admin();
+        require!(role != Role::Owner, "Owner cannot be granted; it is set at init");
+
+        let mut roles = self.roles.get(&account_id).unwrap_or_default();
+        roles.insert(role);
+        self.roles.insert(&account_id, &roles);
+    }
+
+    /// Revoke `role` from `account_id`. Restricted to admins (and the owner).
+    pub fn revoke_role(&mut self, account_id: AccountId, role: Role) {
+        self.assert_admin();
+
+        if let Some(mut roles) = self.roles.get(&account_id) {
+            roles.remove(&role);
+            if roles.is_empty() {
+                self.roles.remove(&account_id);
+            } else {
+                self.roles.insert(&account_id, &roles);
+            }
+        }
+    }
+
+    /// Give up one of your own roles. Anyone may call this on themselves.
+    pub fn renounce_role(&mut self, role: Role) {
+        let caller = env::predecessor_account_id();
+        if let Some(mut roles) = self.roles.get(&caller) {
+            roles.remove(&role);
+            if roles.is_empty() {
+                self.roles.remove(&caller);
+            } else {
+                self.roles.insert(&caller, &roles);
+            }
+        }
+    }
+
+    /// Pause minting and series creation. Restricted to admins (and the owner).
+    pub fn pause(&mut self) {
+        self.assert_admin();
+        self.paused = true;
+    }
+
+    /// Resume minting and series creation. Restricted to admins (and the owner).
+    pub fn unpause(&mut self) {
+        self.assert_admin();
+        self.paused = false;
+    }
+
+    pub fn has_role(&self, account_id: AccountId, role: Role) -> bool {
+        self.internal_has_role(&account_id, role)
+    }
+}
+
+impl Contract {
+    pub(crate) fn internal_has_role(&self, account_id: &AccountId, role: Role) -> bool {
+        if role == Role::Owner {
+            return *account_id == self.owner_id;
+        }
+        *account_id == self.owner_id
+            || self
+                .roles
+                .get(account_id)
+                .map_or(false, |roles| roles.contains(&role))
+    }
+
+    pub(crate) fn assert_role(&self, role: Role) {
+        let caller = env::predecessor_account_id();
+        require!(
+            self.internal_has_role(&caller, role),
+            format!("Caller does not have the {:?} role", role)
+        );
+    }
+
+    pub(crate) fn assert_admin(&self) {
+        let caller = env::predecessor_account_id();
+        require!(
+            self.internal_has_role(&caller, Role::Admin),
+            "Caller is not an admin"
+        );
+    }
+
+    pub(crate) fn assert_owner(&self) {
+        require!(
+            env::predecessor_account_id() == self.owner_id,
+            "Caller is not the owner"
+        );
+    }
+
+    pub(crate) fn assert_not_paused(&self) {
+        require!(!self.paused, "contract is paused");
+    }
+}
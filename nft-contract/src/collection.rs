@@ -0,0 +1,145 @@
+use crate::*;
+
+/// A group of series that a marketplace can trust as a single provenance unit
+/// once `verified_by` is set, rather than inferring grouping from the
+/// `series_id:edition` token-id convention.
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct Collection {
+    pub name: String,
+    pub owner_id: AccountId,
+    pub series_ids: UnorderedSet<u64>,
+    pub verified_by: Option<AccountId>,
+}
+
+/// JSON-friendly view of a `Collection`, returned by the view methods below.
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct JsonCollection {
+    pub collection_id: u64,
+    pub name: String,
+    pub owner_id: AccountId,
+    pub series_ids: Vec<u64>,
+    pub verified: bool,
+}
+
+#[near_bindgen]
+impl Contract {
+    #[payable]
+    pub fn create_collection(&mut self, name: String) -> u64 {
+        let initial_storage_usage = env::storage_usage();
+
+        let caller = env::predecessor_account_id();
+        let collection_id = self.collections_by_id.len() + 1;
+
+        require!(
+            self.collections_by_id
+                .insert(
+                    &collection_id,
+                    &Collection {
+                        name,
+                        owner_id: caller,
+                        series_ids: UnorderedSet::new(StorageKey::CollectionSeriesInner {
+                            collection_id,
+                        }),
+                        verified_by: None,
+                    }
+                )
+                .is_none(),
+            "collection ID already exists"
+        );
+
+        let required_storage_in_bytes = env::storage_usage() - initial_storage_usage;
+        refund_deposit(required_storage_in_bytes);
+
+        collection_id
+    }
+
+    /// Add an existing series to a collection. Restricted to the series owner,
+    /// so a collection can't be padded out with series someone else created,
+    /// and to the collection's owner (or an admin), so a series owner can't
+    /// inject it into someone else's (possibly already-verified) collection.
+    /// A series may only ever belong to one collection.
+    #[payable]
+    pub fn add_series_to_collection(&mut self, collection_id: u64, series_id: u64) {
+        let initial_storage_usage = env::storage_usage();
+
+        let series = self.series_by_id.get(&series_id).expect("Not a series");
+        require!(
+            series.owner_id == env::predecessor_account_id(),
+            "Only the series owner can add it to a collection"
+        );
+
+        require!(
+            self.collection_id_by_series_id.get(&series_id).is_none(),
+            "Series is already in a collection"
+        );
+
+        let mut collection = self
+            .collections_by_id
+            .get(&collection_id)
+            .expect("No collection found");
+        require!(
+            collection.owner_id == env::predecessor_account_id()
+                || self.internal_has_role(&env::predecessor_account_id(), Role::Admin),
+            "Only the collection owner or an admin can add a series to it"
+        );
+        collection.series_ids.insert(&series_id);
+        self.collections_by_id.insert(&collection_id, &collection);
+        self.collection_id_by_series_id.insert(&series_id, &collection_id);
+
+        let required_storage_in_bytes = env::storage_usage() - initial_storage_usage;
+        refund_deposit(required_storage_in_bytes);
+    }
+
+    /// Stamp a collection as verified. Restricted to the contract owner or an admin.
+    pub fn verify_collection(&mut self, collection_id: u64) {
+        self.assert_admin();
+
+        let mut collection = self
+            .collections_by_id
+            .get(&collection_id)
+            .expect("No collection found");
+        collection.verified_by = Some(env::predecessor_account_id());
+        self.collections_by_id.insert(&collection_id, &collection);
+    }
+
+    pub fn get_collection(&self, collection_id: u64) -> Option<JsonCollection> {
+        self.collections_by_id.get(&collection_id).map(|collection| JsonCollection {
+            collection_id,
+            name: collection.name,
+            owner_id: collection.owner_id,
+            series_ids: collection.series_ids.to_vec(),
+            verified: collection.verified_by.is_some(),
+        })
+    }
+
+    /// Look up which (if any) collection a series belongs to, and whether
+    /// that collection has been verified.
+    pub fn get_series_collection(&self, series_id: u64) -> Option<JsonCollection> {
+        let collection_id = self.collection_id_by_series_id.get(&series_id)?;
+        let collection = self.collections_by_id.get(&collection_id)?;
+        Some(JsonCollection {
+            collection_id,
+            name: collection.name,
+            owner_id: collection.owner_id,
+            series_ids: collection.series_ids.to_vec(),
+            verified: collection.verified_by.is_some(),
+        })
+    }
+}
+
+impl Contract {
+    /// `(collection_id, verified)` for a series, for token/series view methods
+    /// to embed without duplicating the `collection_id_by_series_id` lookup.
+    pub(crate) fn collection_info_for_series(&self, series_id: u64) -> (Option<u64>, bool) {
+        let collection_id = match self.collection_id_by_series_id.get(&series_id) {
+            Some(collection_id) => collection_id,
+            None => return (None, false),
+        };
+        let verified = self
+            .collections_by_id
+            .get(&collection_id)
+            .map_or(false, |collection| collection.verified_by.is_some());
+        (Some(collection_id), verified)
+    }
+}
@@ -0,0 +1,214 @@
+use near_sdk::{ext_contract, Gas, PromiseResult};
+
+use crate::*;
+
+/// Gas reserved for the outbound `nft_on_move` call on the destination contract.
+const GAS_FOR_NFT_ON_MOVE: Gas = Gas(30_000_000_000_000);
+/// Gas reserved for the callback that finalizes the local burn.
+const GAS_FOR_ON_MOVE_CALLBACK: Gas = Gas(20_000_000_000_000);
+
+/// Minimal interface implemented by destination contracts that accept moved tokens.
+#[ext_contract(ext_move)]
+trait NftOnMove {
+    fn nft_on_move(&mut self, token: Token, metadata: TokenMetadata, royalty: Option<HashMap<AccountId, u32>>);
+}
+
+#[ext_contract(ext_self)]
+trait NftMoveResolver {
+    fn on_move_callback(&mut self, token_id: TokenId, owner_id: AccountId);
+}
+
+#[near_bindgen]
+impl Contract {
+    /// Owner-only switch for the migration feature. Off by default so existing
+    /// deployments aren't exposed to it until explicitly opted in.
+    pub fn set_allow_moves(&mut self, allow_moves: bool) {
+        self.assert_owner();
+        self.allow_moves = allow_moves;
+    }
+
+    /// Migrate a token to another NEAR NFT contract. Burns the token here only if
+    /// the destination contract accepts it.
+    #[payable]
+    pub fn nft_move(&mut self, token_id: TokenId, contract_id: AccountId) -> Promise {
+        require!(self.allow_moves, "Moves are not enabled on this contract");
+        assert_one_yocto();
+
+        let predecessor_account_id = env::predecessor_account_id();
+        let token = self.tokens_by_id.get(&token_id).expect("No token found");
+        require!(
+            token.owner_id == predecessor_account_id,
+            "Predecessor must be the token owner"
+        );
+        require!(
+            !self.pending_moves.contains(&token_id),
+            "Token already has a move pending"
+        );
+        self.pending_moves.insert(&token_id);
+
+        let series = self
+            .series_by_id
+            .get(&token.series_id)
+            .expect("Not a series");
+
+        ext_move::ext(contract_id)
+            .with_static_gas(GAS_FOR_NFT_ON_MOVE)
+            .nft_on_move(token, series.metadata, series.royalty)
+            .then(
+                ext_self::ext(env::current_account_id())
+                    .with_static_gas(GAS_FOR_ON_MOVE_CALLBACK)
+                    .on_move_callback(token_id, predecessor_account_id),
+            )
+    }
+
+    #[private]
+    pub fn on_move_callback(&mut self, token_id: TokenId, owner_id: AccountId) {
+        require!(env::promise_results_count() == 1, "Expected one promise result");
+
+        // Whatever the outcome, this move is no longer in flight.
+        self.pending_moves.remove(&token_id);
+
+        let destination_accepted = matches!(env::promise_result(0), PromiseResult::Successful(_));
+        if !destination_accepted {
+            // Destination rejected the token (or the call failed); leave it intact.
+            return;
+        }
+
+        // The token may have been transferred, approved, or burned while the
+        // cross-contract call was pending. Re-check against the token's current
+        // owner rather than trusting the `owner_id` captured when `nft_move` was
+        // called, and bail out of the burn if it no longer matches.
+        // Returning (rather than panicking) keeps the `pending_moves` removal above.
+        match self.tokens_by_id.get(&token_id) {
+            Some(token) if token.owner_id == owner_id => {}
+            _ => return,
+        }
+
+        let token = self.tokens_by_id.remove(&token_id).expect("No token found");
+
+        let mut series = self
+            .series_by_id
+            .get(&token.series_id)
+            .expect("Not a series");
+        series.tokens.remove(&token_id);
+        self.series_by_id.insert(&token.series_id, &series);
+
+        self.internal_remove_token_from_owner(&token.owner_id, &token_id);
+
+        let nft_burn_log: EventLog = EventLog {
+            standard: NFT_STANDARD_NAME.to_string(),
+            version: NFT_METADATA_SPEC.to_string(),
+            event: EventLogVariant::NftBurn(vec![NftBurnLog {
+                owner_id: token.owner_id.to_string(),
+                authorized_id: None,
+                token_ids: vec![token_id],
+                memo: Some("moved to another contract".to_string()),
+            }]),
+        };
+        env::log_str(&nft_burn_log.to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use near_sdk::test_utils::{accounts, VMContextBuilder};
+    use near_sdk::testing_env;
+
+    use super::*;
+
+    fn sample_metadata() -> NFTContractMetadata {
+        NFTContractMetadata {
+            spec: "nft-1.0.0".to_string(),
+            name: "Test Series".to_string(),
+            symbol: "TST".to_string(),
+            icon: None,
+            base_uri: None,
+            reference: None,
+            reference_hash: None,
+        }
+    }
+
+    /// Sets up a contract with `allow_moves` on and a single minted token
+    /// owned by `accounts(0)`, with the predecessor set to the same account.
+    fn setup_with_token() -> (Contract, TokenId) {
+        let mut context = VMContextBuilder::new();
+        context.predecessor_account_id(accounts(0));
+        testing_env!(context.build());
+
+        let mut contract = Contract::new(accounts(0), sample_metadata());
+        contract.allow_moves = true;
+
+        let series_id: u64 = 1;
+        contract.series_by_id.insert(
+            &series_id,
+            &Series {
+                mint_id: series_id,
+                metadata: TokenMetadata {
+                    title: None,
+                    description: None,
+                    media: None,
+                    media_hash: None,
+                    copies: None,
+                    issued_at: None,
+                    expires_at: None,
+                    starts_at: None,
+                    updated_at: None,
+                    extra: None,
+                    reference: None,
+                    reference_hash: None,
+                },
+                royalty: None,
+                owner_id: accounts(0),
+                tokens: UnorderedSet::new(StorageKey::SeriesByIdInner {
+                    account_id_hash: hash_account_id(&format!("{}{}", series_id, accounts(0))),
+                }),
+                next_edition: 2,
+            },
+        );
+
+        let token_id = format!("{}:1", series_id);
+        contract.tokens_by_id.insert(
+            &token_id,
+            &Token {
+                series_id,
+                owner_id: accounts(0),
+                approved_account_ids: Default::default(),
+                next_approval_id: 0,
+            },
+        );
+
+        (contract, token_id)
+    }
+
+    #[test]
+    fn nft_move_marks_token_pending() {
+        let (mut contract, token_id) = setup_with_token();
+
+        let mut context = VMContextBuilder::new();
+        context
+            .predecessor_account_id(accounts(0))
+            .attached_deposit(1);
+        testing_env!(context.build());
+
+        contract.nft_move(token_id.clone(), accounts(1));
+        assert!(contract.pending_moves.contains(&token_id));
+    }
+
+    #[test]
+    #[should_panic(expected = "Token already has a move pending")]
+    fn nft_move_rejects_second_move_while_pending() {
+        let (mut contract, token_id) = setup_with_token();
+
+        let mut context = VMContextBuilder::new();
+        context
+            .predecessor_account_id(accounts(0))
+            .attached_deposit(1);
+        testing_env!(context.build());
+
+        contract.nft_move(token_id.clone(), accounts(1));
+        // The owner hasn't changed and nothing has resolved the first move yet;
+        // a second nft_move on the same token must not be allowed to fire another
+        // outbound mint before the first one's callback runs.
+        contract.nft_move(token_id, accounts(2));
+    }
+}
@@ -0,0 +1,86 @@
+use std::collections::HashSet;
+
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::collections::{LazyOption, LookupMap, LookupSet, UnorderedMap, UnorderedSet};
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::{env, near_bindgen, AccountId, Balance, BorshStorageKey, PanicOnDefault, Promise};
+
+pub use crate::access_control::*;
+pub use crate::collection::*;
+pub use crate::cross_contract::*;
+pub use crate::series::*;
+
+mod access_control;
+mod collection;
+mod cross_contract;
+mod series;
+mod upgrade;
+mod validation;
+
+#[near_bindgen]
+#[derive(BorshDeserialize, BorshSerialize, PanicOnDefault)]
+pub struct Contract {
+    pub owner_id: AccountId,
+
+    pub tokens_per_owner: LookupMap<AccountId, UnorderedSet<TokenId>>,
+    pub tokens_by_id: LookupMap<TokenId, Token>,
+
+    pub series_by_id: UnorderedMap<u64, Series>,
+    pub series_id_by_mint_id: LookupMap<u64, u64>,
+
+    pub metadata: LazyOption<NFTContractMetadata>,
+
+    /// Roles granted on top of the implicit `Owner` role held by `owner_id`.
+    pub roles: LookupMap<AccountId, HashSet<Role>>,
+    /// When `true`, `create_series`/`nft_mint` are rejected.
+    pub paused: bool,
+
+    /// Owner-gated switch for `nft_move`; off by default.
+    pub allow_moves: bool,
+    /// Tokens with an `nft_move` in flight. Checked by `nft_move`/`nft_burn` so
+    /// a token can't be moved twice (or burned) before the first move's
+    /// callback finalizes it.
+    pub pending_moves: LookupSet<TokenId>,
+
+    pub collections_by_id: UnorderedMap<u64, Collection>,
+    /// Reverse index so `get_series_collection` doesn't have to scan every
+    /// collection to find the one a series belongs to.
+    pub collection_id_by_series_id: LookupMap<u64, u64>,
+}
+
+#[derive(BorshStorageKey, BorshSerialize)]
+pub(crate) enum StorageKey {
+    TokensPerOwner,
+    TokensPerOwnerInner { account_id_hash: near_sdk::CryptoHash },
+    TokensById,
+    SeriesById,
+    SeriesByIdInner { account_id_hash: near_sdk::CryptoHash },
+    SeriesIdByMintId,
+    Metadata,
+    Roles,
+    PendingMoves,
+    CollectionsById,
+    CollectionSeriesInner { collection_id: u64 },
+    CollectionIdBySeriesId,
+}
+
+#[near_bindgen]
+impl Contract {
+    #[init]
+    pub fn new(owner_id: AccountId, metadata: NFTContractMetadata) -> Self {
+        Self {
+            owner_id,
+            tokens_per_owner: LookupMap::new(StorageKey::TokensPerOwner),
+            tokens_by_id: LookupMap::new(StorageKey::TokensById),
+            series_by_id: UnorderedMap::new(StorageKey::SeriesById),
+            series_id_by_mint_id: LookupMap::new(StorageKey::SeriesIdByMintId),
+            metadata: LazyOption::new(StorageKey::Metadata, Some(&metadata)),
+            roles: LookupMap::new(StorageKey::Roles),
+            paused: false,
+            allow_moves: false,
+            pending_moves: LookupSet::new(StorageKey::PendingMoves),
+            collections_by_id: UnorderedMap::new(StorageKey::CollectionsById),
+            collection_id_by_series_id: LookupMap::new(StorageKey::CollectionIdBySeriesId),
+        }
+    }
+}
@@ -11,6 +11,36 @@ pub struct KeypomArgs {
     pub key_id_field: Option<String>
 }
 
+/// A group of editions minted under a shared `mint_id`, identified by the
+/// `"{series_id}:{edition}"` token-id convention.
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct Series {
+    pub mint_id: u64,
+    pub metadata: TokenMetadata,
+    //we add an optional parameter for perpetual royalties
+    pub royalty: Option<HashMap<AccountId, u32>>,
+    pub owner_id: AccountId,
+    pub tokens: UnorderedSet<TokenId>,
+    /// Next edition number to hand out. Only ever moves forward, so a burned
+    /// edition's id is never reissued to a different token.
+    pub next_edition: u64,
+}
+
+/// JSON-friendly view of a `Series`, returned by `get_series`. Includes the
+/// collection it belongs to (if any) so marketplaces don't need a second
+/// call to `get_series_collection` to know whether a series is verified.
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct JsonSeries {
+    pub series_id: u64,
+    pub mint_id: u64,
+    pub metadata: TokenMetadata,
+    pub royalty: Option<HashMap<AccountId, u32>>,
+    pub owner_id: AccountId,
+    pub collection_id: Option<u64>,
+    pub verified: bool,
+}
+
 #[near_bindgen]
 impl Contract {
     #[payable]
@@ -20,12 +50,14 @@ impl Contract {
         metadata: TokenMetadata,
         royalty: Option<HashMap<AccountId, u32>>,
     ) {
+        self.assert_not_paused();
+
         //measure the initial storage being used on the contract
         let initial_storage_usage = env::storage_usage();
 
         let caller = env::predecessor_account_id();
-        // require caller to be a approved creator
-        require!(self.is_approved_creator(caller.clone()), "MUST BE APPROVED CREATOR TO ADD A SERIES");
+        self.assert_role(Role::Creator);
+        Contract::assert_series_data_valid(&metadata, &royalty);
 
         let series_id: u64 = self.series_by_id.len() + 1;
         let mut final_mint_id = series_id;
@@ -50,13 +82,13 @@ impl Contract {
                     &Series {
                         mint_id: final_mint_id,
                         metadata,
-                        //we add an optional parameter for perpetual royalties
                         royalty,
                         tokens: UnorderedSet::new(StorageKey::SeriesByIdInner {
                             // We get a new unique prefix for the collection
                             account_id_hash: hash_account_id(&format!("{}{}", series_id, caller)),
                         }),
-                        owner_id: caller
+                        owner_id: caller,
+                        next_edition: 1,
                     }
                 )
                 .is_none(),
@@ -72,6 +104,8 @@ impl Contract {
 
     #[payable]
     pub fn nft_mint(&mut self, mint_id: U64, receiver_id: AccountId, keypom_args: KeypomArgs) {
+        self.assert_not_paused();
+
         // Ensure the injected keypom args are not malicious
         require!(keypom_args.drop_id_field.unwrap() == "mint_id".to_string(), "malicious call. Injected keypom args don't match");
         require!(keypom_args.account_id_field.unwrap() == "receiver_id".to_string(), "malicious call. Injected keypom args don't match");
@@ -79,11 +113,7 @@ impl Contract {
         //measure the initial storage being used on the contract
         let initial_storage_usage = env::storage_usage();
 
-        let predecessor = env::predecessor_account_id();
-        assert!(
-            self.approved_minters.contains(&predecessor),
-            "Not approved minter"
-        );
+        self.assert_role(Role::Minter);
 
         let series_id = self
             .series_id_by_mint_id
@@ -99,7 +129,12 @@ impl Contract {
             );
         }
 
-        let token_id = format!("{}:{}", series_id, cur_len + 1);
+        // `tokens.len()` shrinks on burn, so deriving the edition from it would
+        // reassign a freed slot's id to a brand new token. `next_edition` only
+        // ever moves forward, so a burned edition's id is never reissued and the
+        // cost of minting doesn't grow with how many low editions were burned.
+        let token_id = format!("{}:{}", series_id, series.next_edition);
+        series.next_edition += 1;
         series.tokens.insert(&token_id);
         self.series_by_id.insert(&series_id, &series);
 
@@ -163,8 +198,8 @@ impl Contract {
             .expect("mint_id record not found");
         let mut series = self.series_by_id.get(&series_id).expect("Not a series");
         require!(
-            series.owner_id == caller,
-            "Only the owner can add a mint_id for this series_id"
+            series.owner_id == caller || self.internal_has_role(&caller, Role::Admin),
+            "Only the series owner or an admin can add a mint_id for this series_id"
         );
 
         // Add the series to the new ID and make sure the new ID doesn't exist yet
@@ -181,4 +216,80 @@ impl Contract {
         series.mint_id = new_mint_id;
         self.series_by_id.insert(&series_id, &series);
     }
+
+    /// Burn a token, removing it from the owner, the `tokens_by_id` map, and its series.
+    /// Requires exactly one yoctoNEAR and that the caller owns or is approved on the token.
+    #[payable]
+    pub fn nft_burn(&mut self, token_id: String) {
+        assert_one_yocto();
+
+        //measure the initial storage being used on the contract
+        let initial_storage_usage = env::storage_usage();
+
+        let token = self.tokens_by_id.get(&token_id).expect("No token found");
+        require!(
+            !self.pending_moves.contains(&token_id),
+            "Token has a move pending; it cannot be burned until that resolves"
+        );
+
+        let predecessor_account_id = env::predecessor_account_id();
+        let mut authorized_id = None;
+        if predecessor_account_id != token.owner_id {
+            require!(
+                token.approved_account_ids.contains_key(&predecessor_account_id),
+                "Predecessor must be the token owner or approved to burn it"
+            );
+            authorized_id = Some(predecessor_account_id.to_string());
+        }
+
+        // Pull the series so we can drop the token from its `tokens` set.
+        let mut series = self
+            .series_by_id
+            .get(&token.series_id)
+            .expect("Not a series");
+        require!(
+            series.tokens.remove(&token_id),
+            "Token not found in its series"
+        );
+        self.series_by_id.insert(&token.series_id, &series);
+
+        self.tokens_by_id.remove(&token_id);
+        self.internal_remove_token_from_owner(&token.owner_id, &token_id);
+
+        // Construct the burn log as per the events standard.
+        let nft_burn_log: EventLog = EventLog {
+            standard: NFT_STANDARD_NAME.to_string(),
+            version: NFT_METADATA_SPEC.to_string(),
+            event: EventLogVariant::NftBurn(vec![NftBurnLog {
+                owner_id: token.owner_id.to_string(),
+                authorized_id,
+                token_ids: vec![token_id],
+                memo: None,
+            }]),
+        };
+
+        // Log the serialized json.
+        env::log_str(&nft_burn_log.to_string());
+
+        // Refund the storage that was freed by removing the token.
+        let storage_freed = initial_storage_usage - env::storage_usage();
+        let refund_amount = Balance::from(storage_freed) * env::storage_byte_cost();
+        Promise::new(predecessor_account_id).transfer(refund_amount);
+    }
+
+    /// Fetch a series along with the collection it belongs to (if any) and
+    /// that collection's verification status.
+    pub fn get_series(&self, series_id: u64) -> Option<JsonSeries> {
+        let series = self.series_by_id.get(&series_id)?;
+        let (collection_id, verified) = self.collection_info_for_series(series_id);
+        Some(JsonSeries {
+            series_id,
+            mint_id: series.mint_id,
+            metadata: series.metadata,
+            royalty: series.royalty,
+            owner_id: series.owner_id,
+            collection_id,
+            verified,
+        })
+    }
 }
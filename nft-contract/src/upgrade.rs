@@ -0,0 +1,122 @@
+use near_sdk::{Gas, GasWeight};
+
+use crate::*;
+
+/// Gas reserved for the `migrate` call that runs after the new code is deployed.
+const GAS_FOR_MIGRATE_CALL: Gas = Gas(30_000_000_000_000);
+
+#[near_bindgen]
+impl Contract {
+    /// Deploy new contract code and call `migrate()` on it in the same batch
+    /// action, so the upgrade either takes effect in full or not at all.
+    /// Only the stored `owner_id` may call this.
+    pub fn upgrade(&mut self) {
+        self.assert_owner();
+
+        let new_code = env::input().expect("Error: No input").to_vec();
+
+        Promise::new(env::current_account_id())
+            .deploy_contract(new_code)
+            .function_call_weight(
+                "migrate".to_string(),
+                vec![],
+                0,
+                GAS_FOR_MIGRATE_CALL,
+                GasWeight(0),
+            );
+    }
+}
+
+/// Mirrors `Contract`'s layout as of the previous deployed version, so the raw
+/// state bytes can be Borsh-decoded before being reshaped into the current
+/// `Contract`. Update this (and `migrate()` below) every time `Contract` gains,
+/// removes, or reorders a field.
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct OldContract {
+    pub owner_id: AccountId,
+    pub tokens_per_owner: LookupMap<AccountId, UnorderedSet<TokenId>>,
+    pub tokens_by_id: LookupMap<TokenId, Token>,
+    pub series_by_id: UnorderedMap<u64, Series>,
+    pub series_id_by_mint_id: LookupMap<u64, u64>,
+    pub metadata: LazyOption<NFTContractMetadata>,
+    pub roles: LookupMap<AccountId, HashSet<Role>>,
+    pub paused: bool,
+    pub allow_moves: bool,
+}
+
+/// Migrates the contract state from the previous layout to the current one.
+/// Called automatically as the second action of the `upgrade()` batch, against
+/// the newly-deployed code, before the new code ever serves another call.
+#[near_bindgen]
+impl Contract {
+    #[private]
+    #[init(ignore_state)]
+    pub fn migrate() -> Self {
+        let old_state: OldContract = env::state_read().expect("Failed to read old state");
+
+        Self {
+            owner_id: old_state.owner_id,
+            tokens_per_owner: old_state.tokens_per_owner,
+            tokens_by_id: old_state.tokens_by_id,
+            series_by_id: old_state.series_by_id,
+            series_id_by_mint_id: old_state.series_id_by_mint_id,
+            metadata: old_state.metadata,
+            roles: old_state.roles,
+            paused: old_state.paused,
+            allow_moves: old_state.allow_moves,
+            // Fields added after this layout was last deployed; backfill with
+            // their empty defaults.
+            pending_moves: LookupSet::new(StorageKey::PendingMoves),
+            collections_by_id: UnorderedMap::new(StorageKey::CollectionsById),
+            collection_id_by_series_id: LookupMap::new(StorageKey::CollectionIdBySeriesId),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use near_sdk::test_utils::{accounts, VMContextBuilder};
+    use near_sdk::testing_env;
+
+    use super::*;
+
+    #[test]
+    fn migrate_carries_over_old_fields_and_defaults_new_ones() {
+        let mut context = VMContextBuilder::new();
+        context.predecessor_account_id(accounts(0));
+        testing_env!(context.build());
+
+        let old_state = OldContract {
+            owner_id: accounts(0),
+            tokens_per_owner: LookupMap::new(StorageKey::TokensPerOwner),
+            tokens_by_id: LookupMap::new(StorageKey::TokensById),
+            series_by_id: UnorderedMap::new(StorageKey::SeriesById),
+            series_id_by_mint_id: LookupMap::new(StorageKey::SeriesIdByMintId),
+            metadata: LazyOption::new(
+                StorageKey::Metadata,
+                Some(&NFTContractMetadata {
+                    spec: "nft-1.0.0".to_string(),
+                    name: "Old Series".to_string(),
+                    symbol: "OLD".to_string(),
+                    icon: None,
+                    base_uri: None,
+                    reference: None,
+                    reference_hash: None,
+                }),
+            ),
+            roles: LookupMap::new(StorageKey::Roles),
+            paused: true,
+            allow_moves: true,
+        };
+        env::state_write(&old_state);
+
+        let migrated = Contract::migrate();
+
+        assert_eq!(migrated.owner_id, accounts(0));
+        assert!(migrated.paused);
+        assert!(migrated.allow_moves);
+        assert_eq!(migrated.collections_by_id.len(), 0);
+        assert!(migrated.collection_id_by_series_id.get(&1).is_none());
+        assert!(!migrated.pending_moves.contains(&"1:1".to_string()));
+    }
+}
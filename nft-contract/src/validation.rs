@@ -0,0 +1,71 @@
+use crate::*;
+
+/// Basis points sum that a royalty split may not exceed (100%).
+const MAX_ROYALTY_BASIS_POINTS: u32 = 10_000;
+/// Caps the number of royalty recipients so payout loops stay bounded.
+const MAX_ROYALTY_RECIPIENTS: usize = 10;
+
+const MAX_TITLE_LEN: usize = 256;
+const MAX_DESCRIPTION_LEN: usize = 2_048;
+const MAX_URI_LEN: usize = 1_024;
+
+impl Contract {
+    /// Mirrors Metaplex's `assert_data_valid` discipline: reject series with
+    /// royalty splits over 100%, too many recipients, or oversized metadata
+    /// strings before any state is written.
+    pub(crate) fn assert_series_data_valid(
+        metadata: &TokenMetadata,
+        royalty: &Option<HashMap<AccountId, u32>>,
+    ) {
+        if let Some(royalty) = royalty {
+            require!(
+                royalty.len() <= MAX_ROYALTY_RECIPIENTS,
+                format!(
+                    "TooManyCreators: royalty may have at most {} recipients",
+                    MAX_ROYALTY_RECIPIENTS
+                )
+            );
+
+            let mut total_basis_points: u32 = 0;
+            for bps in royalty.values() {
+                require!(
+                    *bps <= MAX_ROYALTY_BASIS_POINTS,
+                    format!(
+                        "RoyaltyTooHigh: a single recipient's basis points ({}) exceeds the max of {}",
+                        bps, MAX_ROYALTY_BASIS_POINTS
+                    )
+                );
+                total_basis_points = total_basis_points
+                    .checked_add(*bps)
+                    .expect("RoyaltyTooHigh: royalty basis points overflowed");
+            }
+            require!(
+                total_basis_points <= MAX_ROYALTY_BASIS_POINTS,
+                format!(
+                    "RoyaltyTooHigh: royalty basis points sum to {} which exceeds the max of {}",
+                    total_basis_points, MAX_ROYALTY_BASIS_POINTS
+                )
+            );
+        }
+
+        require!(
+            metadata.title.as_ref().map_or(0, |s| s.len()) <= MAX_TITLE_LEN,
+            format!("TitleTooLong: metadata.title exceeds {} bytes", MAX_TITLE_LEN)
+        );
+        require!(
+            metadata.description.as_ref().map_or(0, |s| s.len()) <= MAX_DESCRIPTION_LEN,
+            format!(
+                "DescriptionTooLong: metadata.description exceeds {} bytes",
+                MAX_DESCRIPTION_LEN
+            )
+        );
+        require!(
+            metadata.media.as_ref().map_or(0, |s| s.len()) <= MAX_URI_LEN,
+            format!("UriTooLong: metadata.media exceeds {} bytes", MAX_URI_LEN)
+        );
+        require!(
+            metadata.reference.as_ref().map_or(0, |s| s.len()) <= MAX_URI_LEN,
+            format!("UriTooLong: metadata.reference exceeds {} bytes", MAX_URI_LEN)
+        );
+    }
+}